@@ -1,5 +1,6 @@
 use rand::rngs::ThreadRng;
 use rand::thread_rng;
+use rand::Rng;
 use rand_distr::{Distribution, Exp};
 use std::time::{Duration, Instant};
 
@@ -29,59 +30,579 @@ use std::time::{Duration, Instant};
 /// });
 /// ```
 ///
-pub struct PoissonScheduler {
-    rng: ThreadRng,
+/// Waiting for each scheduled time sleeps for the bulk of the remaining interval and only
+/// busy-spins the final stretch of it (the spin threshold), so a scheduler at a low rate doesn't
+/// pin a core at 100%. Use [`PoissonScheduler::with_spin_threshold`] to trade CPU for timing
+/// precision. With the `async` feature enabled, `run_async` awaits a `tokio` timer instead of
+/// blocking, so the scheduler can be embedded in an async load generator without occupying a
+/// whole OS thread per instance.
+///
+pub struct PoissonScheduler<R: Rng = ThreadRng> {
+    rng: R,
     exp: Exp<f64>,
+    spin_threshold: Duration,
 }
 
-impl PoissonScheduler {
-    /// Creates and returns a new `PoissonScheduler` with the given rate. The rate represents
-    /// the average number of events per second. Note that the rate should not exceed 1e9 since
-    /// the inter-arrival times are measured in nanoseconds, and we need to maintain precision.
+/// Default threshold below which `wait_until` busy-spins instead of sleeping, chosen to absorb
+/// typical OS scheduler wake-up jitter while still sleeping for the bulk of a long wait.
+const DEFAULT_SPIN_THRESHOLD: Duration = Duration::from_micros(50);
+
+impl PoissonScheduler<ThreadRng> {
+    /// Creates and returns a new `PoissonScheduler` with the given rate, using `ThreadRng` as a
+    /// convenience. The rate represents the average number of events per second. Note that the
+    /// rate should not exceed 1e9 since the inter-arrival times are measured in nanoseconds, and
+    /// we need to maintain precision.
+    ///
+    /// For reproducible runs (e.g. in deterministic test suites), use
+    /// [`PoissonScheduler::new_with_rng`] with a seeded RNG such as `StdRng` instead.
     ///
     /// # Parameters
     ///
     /// * `rate`: The average number of events per second.
     ///
     pub fn new(rate: f64) -> Self {
+        Self::new_with_rng(rate, thread_rng())
+    }
+}
+
+impl<R: Rng> PoissonScheduler<R> {
+    /// Creates and returns a new `PoissonScheduler` with the given rate and RNG. The rate
+    /// represents the average number of events per second. Note that the rate should not exceed
+    /// 1e9 since the inter-arrival times are measured in nanoseconds, and we need to maintain
+    /// precision.
+    ///
+    /// Passing a seeded RNG (e.g. `StdRng::seed_from_u64(seed)`) makes the generated event
+    /// sequence reproducible across runs.
+    ///
+    /// # Parameters
+    ///
+    /// * `rate`: The average number of events per second.
+    /// * `rng`: The RNG used to sample inter-arrival times.
+    ///
+    pub fn new_with_rng(rate: f64, rng: R) -> Self {
         if rate > 1e9 {
             panic!("Rate should not exceed 1e9 operations per second")
         }
         let lamda = rate / 1e9; // events per nanosecond
         let exp = Exp::new(lamda).expect("Exponential function could not be created.");
-        let rng = thread_rng();
-        PoissonScheduler { rng, exp }
+        PoissonScheduler {
+            rng,
+            exp,
+            spin_threshold: DEFAULT_SPIN_THRESHOLD,
+        }
+    }
+
+    /// Sets the spin threshold used by `wait_until`: once the remaining wait drops below this,
+    /// the scheduler busy-spins instead of sleeping the rest of the way. Lowering it trades CPU
+    /// for timing precision; raising it does the opposite. Defaults to 50µs.
+    pub fn with_spin_threshold(mut self, spin_threshold: Duration) -> Self {
+        self.spin_threshold = spin_threshold;
+        self
     }
 
     /// Schedules and runs the provided closure based on the Poisson process.
     ///
+    /// Offsets are sampled and waited out one at a time rather than precomputed, so this stays
+    /// O(1) in memory and doesn't let generation time eat into the run window; for the
+    /// precomputed variant, see [`PoissonScheduler::sample_events`].
+    ///
     /// # Parameters
     ///
     /// * `runtime`: The total duration (Duration) the scheduler should run.
     /// * `action`: A closure that is called each time an event is scheduled. The closure is passed
-    /// the scheduled `Instant` as a parameter.
+    ///   the scheduled `Instant` as a parameter.
     ///
     pub fn run<F: FnMut(Instant)>(&mut self, runtime: Duration, mut action: F) {
-        let end_time = Instant::now() + runtime;
+        let start_time = Instant::now();
+        let end_time = start_time + runtime;
+        let mut next_time = start_time;
 
-        while Instant::now() < end_time {
+        loop {
             let inter_arrival_time = self.exp.sample(&mut self.rng);
-            let next_time = Instant::now() + Duration::from_nanos(inter_arrival_time as u64);
-            Self::wait_until(next_time);
+            next_time += Duration::from_nanos(inter_arrival_time as u64);
+            if next_time >= end_time {
+                break;
+            }
+            wait_until(next_time, self.spin_threshold);
 
             action(next_time);
         }
     }
 
-    fn wait_until(next: Instant) {
-        let mut current = Instant::now();
-        let mut time_span = next.duration_since(current);
+    /// Like [`PoissonScheduler::run`], but awaits an async timer instead of blocking, so the
+    /// scheduler can be embedded in an async load generator without occupying a whole OS thread
+    /// per instance. Requires the `async` feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `runtime`: The total duration (Duration) the scheduler should run.
+    /// * `action`: A closure that is called each time an event is scheduled and returns the
+    ///   future to await before scheduling the next one. The closure is passed the scheduled
+    ///   `Instant` as a parameter.
+    ///
+    #[cfg(feature = "async")]
+    pub async fn run_async<F, Fut>(&mut self, runtime: Duration, mut action: F)
+    where
+        F: FnMut(Instant) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let start_time = Instant::now();
+
+        for offset in self.sample_events(runtime) {
+            let next_time = start_time + offset;
+            tokio::time::sleep_until(tokio::time::Instant::from_std(next_time)).await;
+
+            action(next_time).await;
+        }
+    }
+
+    /// Generates the full sorted list of event offsets for the given run window without
+    /// dispatching anything, the way the external `point_process` crate returns a `Vec<Event>`.
+    ///
+    /// This lets callers drive their own executor, feed a replay harness, or statistically
+    /// inspect the generated process offline. Offsets are accumulated by repeatedly sampling
+    /// exponential inter-arrival times until the running sum exceeds `runtime`.
+    ///
+    /// # Parameters
+    ///
+    /// * `runtime`: The total duration (Duration) the process should cover.
+    ///
+    pub fn sample_events(&mut self, runtime: Duration) -> Vec<Duration> {
+        let mut events = Vec::new();
+        let mut elapsed = Duration::ZERO;
+
+        loop {
+            let inter_arrival_time = self.exp.sample(&mut self.rng);
+            elapsed += Duration::from_nanos(inter_arrival_time as u64);
+            if elapsed >= runtime {
+                break;
+            }
+            events.push(elapsed);
+        }
+
+        events
+    }
+
+    /// Runs the scheduler like [`PoissonScheduler::run`], but measures each event's latency
+    /// against its *intended* Poisson timestamp rather than its actual dispatch time, and
+    /// returns the aggregated [`LatencyStats`].
+    ///
+    /// This is coordinated-omission correct: the intended schedule is accumulated from the run's
+    /// start time by the Poisson process and is never reset to `Instant::now()`. So if `action` runs slow and
+    /// the loop falls behind, later scheduled times are still computed relative to the original
+    /// schedule, and the resulting delay shows up as latency on those events instead of being
+    /// silently omitted. Read more: https://www.scylladb.com/2021/04/22/on-coordinated-omission/
+    ///
+    /// # Parameters
+    ///
+    /// * `runtime`: The total duration (Duration) the scheduler should run.
+    /// * `action`: A closure that is called each time an event is scheduled. The closure is
+    ///   passed the scheduled `Instant` as a parameter.
+    ///
+    pub fn run_measured<F: FnMut(Instant)>(
+        &mut self,
+        runtime: Duration,
+        mut action: F,
+    ) -> LatencyStats {
+        let start_time = Instant::now();
+        let end_time = start_time + runtime;
+        let mut stats = LatencyStats::new();
+        let mut next_time = start_time;
+
+        loop {
+            let inter_arrival_time = self.exp.sample(&mut self.rng);
+            next_time += Duration::from_nanos(inter_arrival_time as u64);
+            if next_time >= end_time {
+                break;
+            }
+            wait_until(next_time, self.spin_threshold);
+
+            action(next_time);
+
+            let completion = Instant::now();
+            stats.record(completion.saturating_duration_since(next_time));
+        }
+
+        stats
+    }
+}
+
+/// Blocks until `next`, sleeping for the bulk of the remaining interval and only busy-spinning
+/// once the remaining time drops below `spin_threshold`. Sleeping avoids pinning a core at 100%
+/// for the whole wait; the final busy-spin absorbs typical OS scheduler wake-up jitter so the
+/// wake-up stays precise.
+fn wait_until(next: Instant, spin_threshold: Duration) {
+    loop {
+        let now = Instant::now();
+        if now >= next {
+            return;
+        }
+
+        let remaining = next - now;
+        if remaining > spin_threshold {
+            std::thread::sleep(remaining - spin_threshold);
+        } else {
+            while Instant::now() < next {}
+            return;
+        }
+    }
+}
+
+/// Aggregated latency statistics returned by [`PoissonScheduler::run_measured`].
+///
+/// Exposes count, min/max/mean, and arbitrary percentiles computed over the recorded
+/// per-event latencies.
+pub struct LatencyStats {
+    count: u64,
+    min: Duration,
+    max: Duration,
+    sum: Duration,
+    samples_nanos: Vec<u64>,
+    sorted: bool,
+}
+
+impl LatencyStats {
+    fn new() -> Self {
+        LatencyStats {
+            count: 0,
+            min: Duration::MAX,
+            max: Duration::ZERO,
+            sum: Duration::ZERO,
+            samples_nanos: Vec::new(),
+            sorted: false,
+        }
+    }
+
+    fn record(&mut self, latency: Duration) {
+        self.count += 1;
+        self.min = self.min.min(latency);
+        self.max = self.max.max(latency);
+        self.sum += latency;
+        self.samples_nanos.push(latency.as_nanos() as u64);
+        self.sorted = false;
+    }
+
+    /// The number of recorded events.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The smallest recorded latency, or zero if no events were recorded.
+    pub fn min(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.min
+        }
+    }
+
+    /// The largest recorded latency.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// The mean recorded latency, or zero if no events were recorded.
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.sum / self.count as u32
+        }
+    }
+
+    /// The latency at the given percentile (e.g. `99.0` for p99), computed via the
+    /// nearest-rank method over the recorded latency histogram.
+    ///
+    /// Sorts the recorded samples on first use and caches the result, so calling this (or
+    /// [`LatencyStats::p50`], [`LatencyStats::p99`], [`LatencyStats::p999`]) repeatedly doesn't
+    /// re-sort on every call.
+    pub fn percentile(&mut self, p: f64) -> Duration {
+        if self.samples_nanos.is_empty() {
+            return Duration::ZERO;
+        }
+        if !self.sorted {
+            self.samples_nanos.sort_unstable();
+            self.sorted = true;
+        }
+        let rank = ((p / 100.0) * self.samples_nanos.len() as f64).ceil() as usize;
+        let index = rank.saturating_sub(1).min(self.samples_nanos.len() - 1);
+        Duration::from_nanos(self.samples_nanos[index])
+    }
+
+    /// The median (p50) recorded latency.
+    pub fn p50(&mut self) -> Duration {
+        self.percentile(50.0)
+    }
+
+    /// The p99 recorded latency.
+    pub fn p99(&mut self) -> Duration {
+        self.percentile(99.0)
+    }
+
+    /// The p99.9 recorded latency.
+    pub fn p999(&mut self) -> Duration {
+        self.percentile(99.9)
+    }
+}
+
+/// # NonHomogeneousPoissonScheduler
+///
+/// Like [`PoissonScheduler`], but the event rate `λ(t)` is allowed to vary over the run
+/// window instead of staying constant — useful for simulating diurnal traffic shapes or
+/// ramp/spike load tests.
+///
+/// Events are generated using Lewis–Shedler thinning: candidate inter-arrival times are
+/// drawn from an exponential with rate `max_lambda`, the supremum of `λ` over the window, and
+/// each candidate is accepted with probability `λ(t) / max_lambda`. Rejected candidates are
+/// discarded and the process continues, so the realized event stream is a genuine
+/// non-homogeneous Poisson process with intensity `λ(t)`.
+///
+/// `t` passed to `λ` is the number of seconds elapsed since `run` started.
+///
+/// The critical invariant is that `λ(t) <= max_lambda` for every `t` in the run window. If this
+/// is violated the thinning acceptance probability silently exceeds 1 at those points, which
+/// undercounts events there without any visible error; in debug builds this is caught by a
+/// `debug_assert!` on every sample.
+///
+/// ## Examples
+///
+/// ```
+/// use poisson_scheduler::NonHomogeneousPoissonScheduler;
+/// use std::time::Duration;
+///
+/// // Ramp from 10 events/s up to 100 events/s over the run.
+/// let max_lambda = 100.0;
+/// let mut scheduler = NonHomogeneousPoissonScheduler::new(
+///     |t: f64| 10.0 + 90.0 * (t / 1.0).min(1.0),
+///     max_lambda,
+/// );
+///
+/// scheduler.run(Duration::from_millis(100), |timestamp| {
+///     println!("Event scheduled at {:?}", timestamp);
+/// });
+/// ```
+///
+pub struct NonHomogeneousPoissonScheduler<L: Fn(f64) -> f64, R: Rng = ThreadRng> {
+    rng: R,
+    exp: Exp<f64>,
+    lambda: L,
+    max_lambda: f64,
+    spin_threshold: Duration,
+}
+
+impl<L: Fn(f64) -> f64> NonHomogeneousPoissonScheduler<L, ThreadRng> {
+    /// Creates a new `NonHomogeneousPoissonScheduler` for the given intensity function, using
+    /// `ThreadRng` as a convenience.
+    ///
+    /// For reproducible runs (e.g. in deterministic test suites), use
+    /// [`NonHomogeneousPoissonScheduler::new_with_rng`] with a seeded RNG such as `StdRng` instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `lambda`: The intensity function `λ(t)`, where `t` is seconds elapsed since `run`
+    ///   started. Must satisfy `λ(t) <= max_lambda` everywhere in the run window.
+    /// * `max_lambda`: The supremum of `λ` over the run window, used as the proposal rate for
+    ///   thinning. Must not exceed 1e9 for the same precision reasons as [`PoissonScheduler::new`].
+    ///
+    pub fn new(lambda: L, max_lambda: f64) -> Self {
+        Self::new_with_rng(lambda, max_lambda, thread_rng())
+    }
+}
+
+impl<L: Fn(f64) -> f64, R: Rng> NonHomogeneousPoissonScheduler<L, R> {
+    /// Creates a new `NonHomogeneousPoissonScheduler` for the given intensity function and RNG.
+    ///
+    /// Passing a seeded RNG (e.g. `StdRng::seed_from_u64(seed)`) makes the generated event
+    /// sequence reproducible across runs.
+    ///
+    /// # Parameters
+    ///
+    /// * `lambda`: The intensity function `λ(t)`, where `t` is seconds elapsed since `run`
+    ///   started. Must satisfy `λ(t) <= max_lambda` everywhere in the run window.
+    /// * `max_lambda`: The supremum of `λ` over the run window, used as the proposal rate for
+    ///   thinning. Must not exceed 1e9 for the same precision reasons as [`PoissonScheduler::new`].
+    /// * `rng`: The RNG used to sample inter-arrival times and thinning draws.
+    ///
+    pub fn new_with_rng(lambda: L, max_lambda: f64, rng: R) -> Self {
+        if max_lambda > 1e9 {
+            panic!("Rate should not exceed 1e9 operations per second")
+        }
+        let lamda = max_lambda / 1e9; // events per nanosecond
+        let exp = Exp::new(lamda).expect("Exponential function could not be created.");
+        NonHomogeneousPoissonScheduler {
+            rng,
+            exp,
+            lambda,
+            max_lambda,
+            spin_threshold: DEFAULT_SPIN_THRESHOLD,
+        }
+    }
+
+    /// Sets the spin threshold used by `wait_until`; see [`PoissonScheduler::with_spin_threshold`].
+    pub fn with_spin_threshold(mut self, spin_threshold: Duration) -> Self {
+        self.spin_threshold = spin_threshold;
+        self
+    }
+
+    /// Schedules and runs the provided closure based on the non-homogeneous Poisson process,
+    /// thinning candidate events against `λ(t) / max_lambda`.
+    ///
+    /// # Parameters
+    ///
+    /// * `runtime`: The total duration (Duration) the scheduler should run.
+    /// * `action`: A closure that is called each time an event is accepted. The closure is
+    ///   passed the scheduled `Instant` as a parameter.
+    ///
+    pub fn run<F: FnMut(Instant)>(&mut self, runtime: Duration, mut action: F) {
+        let start_time = Instant::now();
+        let end_time = start_time + runtime;
+        let mut next_time = start_time;
+
+        loop {
+            let inter_arrival_time = self.exp.sample(&mut self.rng);
+            next_time += Duration::from_nanos(inter_arrival_time as u64);
+
+            if next_time >= end_time {
+                break;
+            }
+
+            wait_until(next_time, self.spin_threshold);
+
+            let t = next_time.duration_since(start_time).as_secs_f64();
+            let lambda_t = (self.lambda)(t);
+            debug_assert!(
+                lambda_t <= self.max_lambda,
+                "lambda(t) exceeded max_lambda at t={}: {} > {}",
+                t,
+                lambda_t,
+                self.max_lambda
+            );
+
+            let u: f64 = self.rng.gen();
+            if u < lambda_t / self.max_lambda {
+                action(next_time);
+            }
+        }
+    }
+}
+
+/// # CompoundPoissonScheduler
+///
+/// Extends the bare-timestamp Poisson process into a compound Poisson process, where each event
+/// carries a random "mark" (jump size) drawn from a configurable distribution `D` — useful for
+/// simulating request payload sizes, financial jump-diffusion shocks, or weighted load.
+///
+/// This reuses the same exponential inter-arrival timing as [`PoissonScheduler`] for the event
+/// stream; at each event it additionally samples one value from the mark distribution. The
+/// running sum of sampled marks, exposed via [`CompoundPoissonScheduler::cumulative_mark`], is
+/// the defining quantity of a compound Poisson process.
+///
+/// ## Examples
+///
+/// ```
+/// use poisson_scheduler::CompoundPoissonScheduler;
+/// use rand_distr::Exp;
+/// use std::time::Duration;
+///
+/// let rate = 50.0; // 50 events per second
+/// let mark_distribution = Exp::new(1.0).unwrap(); // mean jump size of 1.0
+/// let mut scheduler = CompoundPoissonScheduler::new(rate, mark_distribution);
+///
+/// scheduler.run(Duration::from_millis(100), |timestamp, mark| {
+///     println!("Event at {:?} with mark {}", timestamp, mark);
+/// });
+///
+/// println!("Cumulative mark: {}", scheduler.cumulative_mark());
+/// ```
+///
+pub struct CompoundPoissonScheduler<R: Rng, D: Distribution<f64>> {
+    rng: R,
+    exp: Exp<f64>,
+    mark_distribution: D,
+    spin_threshold: Duration,
+    cumulative_mark: f64,
+}
+
+impl<D: Distribution<f64>> CompoundPoissonScheduler<ThreadRng, D> {
+    /// Creates and returns a new `CompoundPoissonScheduler` with the given rate and mark
+    /// distribution, using `ThreadRng` as a convenience. The rate represents the average number
+    /// of events per second; see [`PoissonScheduler::new`] for the same precision caveat.
+    ///
+    /// # Parameters
+    ///
+    /// * `rate`: The average number of events per second.
+    /// * `mark_distribution`: The distribution `D` that each event's mark is sampled from.
+    ///
+    pub fn new(rate: f64, mark_distribution: D) -> Self {
+        Self::new_with_rng(rate, mark_distribution, thread_rng())
+    }
+}
+
+impl<R: Rng, D: Distribution<f64>> CompoundPoissonScheduler<R, D> {
+    /// Creates and returns a new `CompoundPoissonScheduler` with the given rate, mark
+    /// distribution, and RNG.
+    ///
+    /// # Parameters
+    ///
+    /// * `rate`: The average number of events per second.
+    /// * `mark_distribution`: The distribution `D` that each event's mark is sampled from.
+    /// * `rng`: The RNG used to sample inter-arrival times and marks.
+    ///
+    pub fn new_with_rng(rate: f64, mark_distribution: D, rng: R) -> Self {
+        if rate > 1e9 {
+            panic!("Rate should not exceed 1e9 operations per second")
+        }
+        let lamda = rate / 1e9; // events per nanosecond
+        let exp = Exp::new(lamda).expect("Exponential function could not be created.");
+        CompoundPoissonScheduler {
+            rng,
+            exp,
+            mark_distribution,
+            spin_threshold: DEFAULT_SPIN_THRESHOLD,
+            cumulative_mark: 0.0,
+        }
+    }
+
+    /// Sets the spin threshold used by `wait_until`; see [`PoissonScheduler::with_spin_threshold`].
+    pub fn with_spin_threshold(mut self, spin_threshold: Duration) -> Self {
+        self.spin_threshold = spin_threshold;
+        self
+    }
+
+    /// Schedules and runs the provided closure based on the compound Poisson process. At each
+    /// scheduled time, a mark is sampled from the mark distribution and passed to the closure
+    /// alongside the `Instant`, and accumulated into [`CompoundPoissonScheduler::cumulative_mark`].
+    ///
+    /// # Parameters
+    ///
+    /// * `runtime`: The total duration (Duration) the scheduler should run.
+    /// * `action`: A closure that is called each time an event is scheduled. The closure is
+    ///   passed the scheduled `Instant` and the sampled mark as parameters.
+    ///
+    pub fn run<F: FnMut(Instant, f64)>(&mut self, runtime: Duration, mut action: F) {
+        let start_time = Instant::now();
+        let mut elapsed = Duration::ZERO;
 
-        while time_span.as_secs_f64() > 0.0 {
-            current = Instant::now();
-            time_span = next.duration_since(current);
+        loop {
+            let inter_arrival_time = self.exp.sample(&mut self.rng);
+            elapsed += Duration::from_nanos(inter_arrival_time as u64);
+            if elapsed >= runtime {
+                break;
+            }
+            let next_time = start_time + elapsed;
+            wait_until(next_time, self.spin_threshold);
+
+            let mark = self.mark_distribution.sample(&mut self.rng);
+            self.cumulative_mark += mark;
+            action(next_time, mark);
         }
     }
+
+    /// The running sum of all marks sampled so far across calls to `run` — the defining
+    /// quantity of a compound Poisson process.
+    pub fn cumulative_mark(&self) -> f64 {
+        self.cumulative_mark
+    }
 }
 
 #[cfg(test)]
@@ -104,7 +625,7 @@ mod tests {
             scheduler.run(runtime, |_| {
                 counter += 1;
             });
-            if counter >= 3 && counter <= 17 {
+            if (3..=17).contains(&counter) {
                 expected_in_range += 1;
             }
         }
@@ -134,7 +655,27 @@ mod tests {
 
         // When
         let start_time = Instant::now();
-        PoissonScheduler::wait_until(target_time);
+        super::wait_until(target_time, super::DEFAULT_SPIN_THRESHOLD);
+        let elapsed_time = start_time.elapsed();
+
+        // Then
+        assert!(
+            elapsed_time >= delay,
+            "Wait function did not delay for at least the target duration"
+        );
+    }
+
+    #[test]
+    fn test_with_spin_threshold_still_waits_the_full_delay() {
+        // Given: a scheduler tuned to busy-spin the entire wait
+        let rate = 10.0;
+        let scheduler = PoissonScheduler::new(rate).with_spin_threshold(Duration::from_secs(1));
+        let delay = Duration::from_millis(100);
+
+        // When
+        let start_time = Instant::now();
+        let target_time = start_time + delay;
+        super::wait_until(target_time, scheduler.spin_threshold);
         let elapsed_time = start_time.elapsed();
 
         // Then
@@ -143,4 +684,232 @@ mod tests {
             "Wait function did not delay for at least the target duration"
         );
     }
+
+    #[test]
+    fn test_non_homogeneous_poisson_scheduler_constant_lambda() {
+        // Given: lambda(t) == max_lambda everywhere, so thinning should accept every candidate
+        // and behave like a homogeneous PoissonScheduler at the same rate.
+        let rate = 10.0; // 10 events per second
+        let runtime = Duration::new(1, 0); // 1 second
+        let mut expected_in_range = 0;
+
+        for _ in 0..10 {
+            let mut scheduler = super::NonHomogeneousPoissonScheduler::new(move |_t| rate, rate);
+
+            // When
+            let mut counter = 0;
+            scheduler.run(runtime, |_| {
+                counter += 1;
+            });
+            if (3..=17).contains(&counter) {
+                expected_in_range += 1;
+            }
+        }
+        // Same reasoning as test_poisson_scheduler_rate: 95% of observations should fall within
+        // lambda +/- 2*SD.
+        assert!(
+            expected_in_range >= 9,
+            "Expected around 95% events in the range. got {}",
+            expected_in_range
+        );
+    }
+
+    #[test]
+    fn test_non_homogeneous_poisson_scheduler_zero_lambda_accepts_nothing() {
+        // Given: lambda(t) == 0 everywhere, so every candidate should be thinned away.
+        let max_lambda = 50.0;
+        let mut scheduler = super::NonHomogeneousPoissonScheduler::new(|_t| 0.0, max_lambda);
+
+        // When
+        let mut counter = 0;
+        scheduler.run(Duration::from_millis(200), |_| {
+            counter += 1;
+        });
+
+        // Then
+        assert_eq!(counter, 0, "No events should have been accepted");
+    }
+
+    #[test]
+    fn test_non_homogeneous_with_spin_threshold_still_waits_the_full_delay() {
+        // Given: a scheduler tuned to busy-spin the entire wait
+        let max_lambda = 10.0;
+        let scheduler = super::NonHomogeneousPoissonScheduler::new(|_t| max_lambda, max_lambda)
+            .with_spin_threshold(Duration::from_secs(1));
+        let delay = Duration::from_millis(100);
+
+        // When
+        let start_time = Instant::now();
+        let target_time = start_time + delay;
+        super::wait_until(target_time, scheduler.spin_threshold);
+        let elapsed_time = start_time.elapsed();
+
+        // Then
+        assert!(
+            elapsed_time >= delay,
+            "Wait function did not delay for at least the target duration"
+        );
+    }
+
+    #[test]
+    fn test_run_measured_records_latency_per_event() {
+        // Given
+        let rate = 50.0; // 50 events per second
+        let mut scheduler = PoissonScheduler::new(rate);
+
+        // When
+        let mut stats = scheduler.run_measured(Duration::from_millis(200), |_| {
+            std::thread::sleep(Duration::from_micros(100));
+        });
+
+        // Then
+        assert!(stats.count() > 0, "Expected at least one recorded event");
+        assert!(stats.mean() >= Duration::from_micros(100));
+        assert!(stats.max() >= stats.min());
+        assert!(stats.p99() >= stats.p50());
+    }
+
+    #[test]
+    fn test_latency_stats_percentile_on_empty_is_zero() {
+        let mut stats = super::LatencyStats::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), Duration::ZERO);
+        assert_eq!(stats.p50(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_sample_events_returns_sorted_offsets_within_runtime() {
+        // Given
+        let rate = 50.0; // 50 events per second
+        let runtime = Duration::from_millis(500);
+        let mut scheduler = PoissonScheduler::new(rate);
+
+        // When
+        let events = scheduler.sample_events(runtime);
+
+        // Then
+        assert!(events.windows(2).all(|w| w[0] <= w[1]), "offsets must be sorted");
+        assert!(
+            events.iter().all(|&offset| offset < runtime),
+            "all offsets must fall within the run window"
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_run_async_dispatches_events_in_order_within_runtime() {
+        // Given
+        let rate = 100.0; // 100 events per second
+        let runtime = Duration::from_millis(200);
+        let mut scheduler = PoissonScheduler::new(rate);
+        let start_time = Instant::now();
+        let mut timestamps = Vec::new();
+
+        // When
+        scheduler
+            .run_async(runtime, |timestamp| {
+                timestamps.push(timestamp);
+                async {}
+            })
+            .await;
+
+        // Then
+        assert!(!timestamps.is_empty(), "Expected at least one event");
+        assert!(
+            timestamps.windows(2).all(|w| w[0] <= w[1]),
+            "events must be dispatched in schedule order"
+        );
+        assert!(
+            timestamps.iter().all(|&t| t >= start_time),
+            "no event should be dispatched before the run started"
+        );
+    }
+
+    #[test]
+    fn test_new_with_rng_is_reproducible() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        // Given: two schedulers seeded identically
+        let rate = 20.0;
+        let runtime = Duration::from_millis(200);
+
+        let mut scheduler_a = PoissonScheduler::new_with_rng(rate, StdRng::seed_from_u64(42));
+        let mut counter_a = 0;
+        scheduler_a.run(runtime, |_| counter_a += 1);
+
+        let mut scheduler_b = PoissonScheduler::new_with_rng(rate, StdRng::seed_from_u64(42));
+        let mut counter_b = 0;
+        scheduler_b.run(runtime, |_| counter_b += 1);
+
+        // Then: the same seed should drive both through the same inter-arrival samples
+        assert_eq!(
+            counter_a, counter_b,
+            "Same seed should produce the same number of events"
+        );
+    }
+
+    #[test]
+    fn test_non_homogeneous_new_with_rng_is_reproducible() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        // Given: two schedulers seeded identically
+        let max_lambda = 100.0;
+        let runtime = Duration::from_millis(200);
+
+        let mut scheduler_a = super::NonHomogeneousPoissonScheduler::new_with_rng(
+            |t: f64| 10.0 + 90.0 * (t / 1.0).min(1.0),
+            max_lambda,
+            StdRng::seed_from_u64(42),
+        );
+        let mut counter_a = 0;
+        scheduler_a.run(runtime, |_| counter_a += 1);
+
+        let mut scheduler_b = super::NonHomogeneousPoissonScheduler::new_with_rng(
+            |t: f64| 10.0 + 90.0 * (t / 1.0).min(1.0),
+            max_lambda,
+            StdRng::seed_from_u64(42),
+        );
+        let mut counter_b = 0;
+        scheduler_b.run(runtime, |_| counter_b += 1);
+
+        // Then: the same seed should drive both through the same inter-arrival and thinning draws
+        assert_eq!(
+            counter_a, counter_b,
+            "Same seed should produce the same number of events"
+        );
+    }
+
+    #[test]
+    fn test_compound_poisson_scheduler_accumulates_marks() {
+        use rand_distr::Exp;
+
+        // Given: a mark distribution that always samples exactly 2.0
+        struct ConstantMark(f64);
+        impl rand::distributions::Distribution<f64> for ConstantMark {
+            fn sample<RNG: rand::Rng + ?Sized>(&self, _rng: &mut RNG) -> f64 {
+                self.0
+            }
+        }
+
+        let rate = 50.0; // 50 events per second
+        let mut scheduler =
+            super::CompoundPoissonScheduler::new(rate, ConstantMark(2.0));
+
+        // When
+        let mut marks = Vec::new();
+        scheduler.run(Duration::from_millis(100), |_timestamp, mark| {
+            marks.push(mark);
+        });
+
+        // Then
+        assert!(!marks.is_empty(), "Expected at least one event");
+        assert!(marks.iter().all(|&m| m == 2.0));
+        assert_eq!(scheduler.cumulative_mark(), marks.len() as f64 * 2.0);
+
+        // And: Exp<f64> also implements Distribution<f64>, so it works out of the box too
+        let _scheduler_with_exp =
+            super::CompoundPoissonScheduler::new(rate, Exp::new(1.0).unwrap());
+    }
 }